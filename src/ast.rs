@@ -0,0 +1,145 @@
+//! Abstract syntax tree for the exercise language.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    I32,
+    Bool,
+    Str,
+    Char,
+    Vec(Box<Type>),
+    /// An iterator pipeline, e.g. the result of `1..=n` or `.map(...)`.
+    ///
+    /// Adaptors eagerly materialize into a `Vec` rather than staying lazy
+    /// (see `Value::Iter`): for the source sizes these exercises deal with,
+    /// the simplicity of "every adaptor returns a concrete list" outweighs
+    /// the cost of not fusing passes.
+    Iter(Box<Type>),
+    Unit,
+}
+
+#[derive(Debug, Clone)]
+pub struct Param {
+    pub name: String,
+    pub ty: Type,
+}
+
+#[derive(Debug, Clone)]
+pub struct FnDecl {
+    pub name: String,
+    pub params: Vec<Param>,
+    pub ret: Type,
+    pub body: Vec<Stmt>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Stmt {
+    Let {
+        name: String,
+        mutable: bool,
+        ty: Option<Type>,
+        value: Expr,
+    },
+    Assign {
+        target: LValue,
+        value: Expr,
+    },
+    ExprStmt(Expr),
+    Return(Option<Expr>),
+    If {
+        cond: Expr,
+        then_branch: Vec<Stmt>,
+        else_branch: Option<Vec<Stmt>>,
+    },
+    While {
+        cond: Expr,
+        body: Vec<Stmt>,
+    },
+    /// `for var in iter { body }`.
+    ForIn {
+        var: String,
+        iter: Expr,
+        body: Vec<Stmt>,
+    },
+    Continue,
+}
+
+#[derive(Debug, Clone)]
+pub enum LValue {
+    Ident(String),
+    Index(Box<LValue>, Expr),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    IntLit(i32),
+    BoolLit(bool),
+    StrLit(String),
+    CharLit(char),
+    Ident(String),
+    Unary(UnOp, Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    /// Short-circuiting `&&`.
+    And(Box<Expr>, Box<Expr>),
+    /// Short-circuiting `||`.
+    Or(Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+    /// `Type::method(args)`, e.g. `Vec::filled(n, 0)`.
+    PathCall(String, String, Vec<Expr>),
+    /// `recv.method(args)`, e.g. `dp.len()`.
+    MethodCall(Box<Expr>, String, Vec<Expr>),
+    Index(Box<Expr>, Box<Expr>),
+    /// `lo..hi` (exclusive) or `lo..=hi` (inclusive).
+    Range(Box<Expr>, Box<Expr>, bool),
+    /// `|params| body`. Only valid as an argument to an iterator adaptor
+    /// like `map`/`filter`.
+    Closure(Vec<String>, Box<Expr>),
+    /// `&expr`, used to pass a collection to `for x in &collection`.
+    Ref(Box<Expr>),
+    Match(Box<Expr>, Vec<MatchArm>),
+}
+
+/// A single pattern usable in a `match` arm.
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    IntLit(i32),
+    StrLit(String),
+    CharLit(char),
+    BoolLit(bool),
+    /// An inclusive range pattern, e.g. `"a"..="z"` or `1..=5`.
+    Range(Box<Pattern>, Box<Pattern>),
+    Wildcard,
+}
+
+/// `pattern1 | pattern2 | ... => body`.
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub patterns: Vec<Pattern>,
+    pub body: Expr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnOp {
+    Neg,
+    Not,
+}
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    pub functions: Vec<FnDecl>,
+    pub top_level: Vec<Stmt>,
+}
@@ -0,0 +1,384 @@
+//! Tree-walking evaluator.
+
+use crate::ast::*;
+use crate::value::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+type Env = HashMap<String, Value>;
+type Funcs<'a> = HashMap<String, &'a FnDecl>;
+
+/// What a block/statement did to control flow.
+enum Flow {
+    Normal,
+    Return(Value),
+    Continue,
+}
+
+pub fn run(program: &Program) -> Result<Value, String> {
+    let mut funcs = HashMap::new();
+    for f in &program.functions {
+        funcs.insert(f.name.clone(), f);
+    }
+    let mut env = Env::new();
+    exec_block(&program.top_level, &mut env, &funcs)?;
+    env.get("answer")
+        .cloned()
+        .ok_or_else(|| "program does not bind 'answer'".to_string())
+}
+
+fn call_function(name: &str, args: Vec<Value>, funcs: &Funcs) -> Result<Value, String> {
+    let decl = funcs
+        .get(name)
+        .ok_or_else(|| format!("call to undeclared function '{name}'"))?;
+    let mut env = Env::new();
+    for (param, arg) in decl.params.iter().zip(args) {
+        env.insert(param.name.clone(), arg);
+    }
+    match exec_block(&decl.body, &mut env, funcs)? {
+        Flow::Return(v) => Ok(v),
+        Flow::Normal => Ok(Value::I32(0)),
+        Flow::Continue => Err(format!("'continue' used outside of a loop in '{name}'")),
+    }
+}
+
+fn exec_block(stmts: &[Stmt], env: &mut Env, funcs: &Funcs) -> Result<Flow, String> {
+    for stmt in stmts {
+        match exec_stmt(stmt, env, funcs)? {
+            Flow::Normal => {}
+            flow => return Ok(flow),
+        }
+    }
+    Ok(Flow::Normal)
+}
+
+fn exec_stmt(stmt: &Stmt, env: &mut Env, funcs: &Funcs) -> Result<Flow, String> {
+    match stmt {
+        Stmt::Let { name, value, .. } => {
+            let v = eval_expr(value, env, funcs)?;
+            env.insert(name.clone(), v);
+            Ok(Flow::Normal)
+        }
+        Stmt::Assign { target, value } => {
+            let v = eval_expr(value, env, funcs)?;
+            assign_lvalue(target, v, env, funcs)?;
+            Ok(Flow::Normal)
+        }
+        Stmt::ExprStmt(expr) => {
+            eval_expr(expr, env, funcs)?;
+            Ok(Flow::Normal)
+        }
+        Stmt::Return(value) => {
+            let v = match value {
+                Some(expr) => eval_expr(expr, env, funcs)?,
+                None => Value::I32(0),
+            };
+            Ok(Flow::Return(v))
+        }
+        Stmt::If {
+            cond,
+            then_branch,
+            else_branch,
+        } => {
+            if eval_expr(cond, env, funcs)?.as_bool()? {
+                exec_block(then_branch, env, funcs)
+            } else if let Some(else_branch) = else_branch {
+                exec_block(else_branch, env, funcs)
+            } else {
+                Ok(Flow::Normal)
+            }
+        }
+        Stmt::While { cond, body } => {
+            while eval_expr(cond, env, funcs)?.as_bool()? {
+                match exec_block(body, env, funcs)? {
+                    Flow::Normal | Flow::Continue => {}
+                    Flow::Return(v) => return Ok(Flow::Return(v)),
+                }
+            }
+            Ok(Flow::Normal)
+        }
+        Stmt::ForIn { var, iter, body } => {
+            let items = iterate_items(eval_expr(iter, env, funcs)?)?;
+            for item in items {
+                env.insert(var.clone(), item);
+                match exec_block(body, env, funcs)? {
+                    Flow::Normal | Flow::Continue => {}
+                    Flow::Return(v) => return Ok(Flow::Return(v)),
+                }
+            }
+            Ok(Flow::Normal)
+        }
+        Stmt::Continue => Ok(Flow::Continue),
+    }
+}
+
+fn assign_lvalue(target: &LValue, value: Value, env: &mut Env, funcs: &Funcs) -> Result<(), String> {
+    match target {
+        LValue::Ident(name) => {
+            env.insert(name.clone(), value);
+            Ok(())
+        }
+        LValue::Index(base, index) => {
+            let vec = resolve_lvalue_vec(base, env, funcs)?;
+            let idx = eval_expr(index, env, funcs)?.as_i32()? as usize;
+            let mut vec = vec.borrow_mut();
+            match vec.get_mut(idx) {
+                Some(slot) => {
+                    *slot = value;
+                    Ok(())
+                }
+                None => Err(format!("index {idx} out of bounds")),
+            }
+        }
+    }
+}
+
+fn resolve_lvalue_vec(
+    target: &LValue,
+    env: &Env,
+    funcs: &Funcs,
+) -> Result<Rc<RefCell<Vec<Value>>>, String> {
+    match target {
+        LValue::Ident(name) => env
+            .get(name)
+            .ok_or_else(|| format!("use of undeclared variable '{name}'"))?
+            .as_vec(),
+        LValue::Index(base, index) => {
+            let vec = resolve_lvalue_vec(base, env, funcs)?;
+            let idx = eval_expr(index, env, funcs)?.as_i32()? as usize;
+            let elem = vec
+                .borrow()
+                .get(idx)
+                .cloned()
+                .ok_or_else(|| format!("index {idx} out of bounds"))?;
+            elem.as_vec()
+        }
+    }
+}
+
+/// Extracts the elements to drive a `for` loop from an iterator pipeline,
+/// a `Vec` (e.g. `for x in &collection`), or a string (its `char`s).
+fn iterate_items(value: Value) -> Result<Vec<Value>, String> {
+    match value {
+        Value::Vec(items) => Ok(items.borrow().clone()),
+        other => other.into_iter_items(),
+    }
+}
+
+fn expect_closure(expr: &Expr) -> Result<(&str, &Expr), String> {
+    match expr {
+        Expr::Closure(params, body) if params.len() == 1 => Ok((params[0].as_str(), body)),
+        other => Err(format!("expected a 1-parameter closure, found {other:?}")),
+    }
+}
+
+fn eval_expr(expr: &Expr, env: &Env, funcs: &Funcs) -> Result<Value, String> {
+    match expr {
+        Expr::IntLit(n) => Ok(Value::I32(*n)),
+        Expr::BoolLit(b) => Ok(Value::Bool(*b)),
+        Expr::StrLit(s) => Ok(Value::Str(s.clone())),
+        Expr::CharLit(c) => Ok(Value::Char(*c)),
+        Expr::Ident(name) => env
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("use of undeclared variable '{name}'")),
+        Expr::Unary(UnOp::Neg, inner) => {
+            let v = eval_expr(inner, env, funcs)?.as_i32()?;
+            Ok(Value::I32(-v))
+        }
+        Expr::Unary(UnOp::Not, inner) => {
+            let v = eval_expr(inner, env, funcs)?.as_bool()?;
+            Ok(Value::Bool(!v))
+        }
+        Expr::Binary(op, lhs, rhs) => {
+            let lhs = eval_expr(lhs, env, funcs)?;
+            let rhs = eval_expr(rhs, env, funcs)?;
+            eval_binary(*op, lhs, rhs)
+        }
+        Expr::And(lhs, rhs) => {
+            if !eval_expr(lhs, env, funcs)?.as_bool()? {
+                return Ok(Value::Bool(false));
+            }
+            Ok(Value::Bool(eval_expr(rhs, env, funcs)?.as_bool()?))
+        }
+        Expr::Or(lhs, rhs) => {
+            if eval_expr(lhs, env, funcs)?.as_bool()? {
+                return Ok(Value::Bool(true));
+            }
+            Ok(Value::Bool(eval_expr(rhs, env, funcs)?.as_bool()?))
+        }
+        Expr::Call(name, args) => {
+            let args = args
+                .iter()
+                .map(|a| eval_expr(a, env, funcs))
+                .collect::<Result<Vec<_>, _>>()?;
+            call_function(name, args, funcs)
+        }
+        Expr::PathCall(ty_name, method, args) => match (ty_name.as_str(), method.as_str()) {
+            ("Vec", "filled") => {
+                let len = eval_expr(&args[0], env, funcs)?.as_i32()?;
+                let mut items = Vec::with_capacity(len.max(0) as usize);
+                for _ in 0..len {
+                    // Re-evaluate the fill expression for every slot so that
+                    // `Vec::filled(n, Vec::filled(m, 0))` produces independent
+                    // rows instead of aliasing the same inner vector.
+                    items.push(eval_expr(&args[1], env, funcs)?);
+                }
+                Ok(Value::Vec(Rc::new(RefCell::new(items))))
+            }
+            (ty_name, method) => Err(format!("unknown associated function '{ty_name}::{method}'")),
+        },
+        Expr::MethodCall(recv, method, args) => {
+            let recv = eval_expr(recv, env, funcs)?;
+            match method.as_str() {
+                "len" if args.is_empty() => Ok(Value::I32(recv.as_vec()?.borrow().len() as i32)),
+                "rev" if args.is_empty() => {
+                    let mut items = recv.into_iter_items()?;
+                    items.reverse();
+                    Ok(Value::Iter(items))
+                }
+                "take" if args.len() == 1 => {
+                    let n = eval_expr(&args[0], env, funcs)?.as_i32()? as usize;
+                    let items = recv.into_iter_items()?;
+                    Ok(Value::Iter(items.into_iter().take(n).collect()))
+                }
+                "map" if args.len() == 1 => {
+                    let (param, body) = expect_closure(&args[0])?;
+                    let mut out = Vec::new();
+                    for item in recv.into_iter_items()? {
+                        let mut closure_env = env.clone();
+                        closure_env.insert(param.to_string(), item);
+                        out.push(eval_expr(body, &closure_env, funcs)?);
+                    }
+                    Ok(Value::Iter(out))
+                }
+                "filter" if args.len() == 1 => {
+                    let (param, body) = expect_closure(&args[0])?;
+                    let mut out = Vec::new();
+                    for item in recv.into_iter_items()? {
+                        let mut closure_env = env.clone();
+                        closure_env.insert(param.to_string(), item.clone());
+                        if eval_expr(body, &closure_env, funcs)?.as_bool()? {
+                            out.push(item);
+                        }
+                    }
+                    Ok(Value::Iter(out))
+                }
+                "sum" if args.is_empty() => {
+                    let mut total = 0;
+                    for item in recv.into_iter_items()? {
+                        total += item.as_i32()?;
+                    }
+                    Ok(Value::I32(total))
+                }
+                "count" if args.is_empty() => Ok(Value::I32(recv.into_iter_items()?.len() as i32)),
+                "is_whitespace" if args.is_empty() => Ok(Value::Bool(recv.as_char()?.is_whitespace())),
+                "is_uppercase" if args.is_empty() => Ok(Value::Bool(recv.as_char()?.is_uppercase())),
+                "is_lowercase" if args.is_empty() => Ok(Value::Bool(recv.as_char()?.is_lowercase())),
+                "is_alphabetic" if args.is_empty() => Ok(Value::Bool(recv.as_char()?.is_alphabetic())),
+                "to_lowercase" if args.is_empty() => {
+                    let lowered = recv.as_char()?.to_lowercase().next().unwrap();
+                    Ok(Value::Char(lowered))
+                }
+                "to_ascii_digit" if args.is_empty() => {
+                    let c = recv.as_char()?;
+                    Ok(Value::I32(c.to_digit(10).map(|d| d as i32).unwrap_or(-1)))
+                }
+                other => Err(format!("no method '{other}' on {recv:?}")),
+            }
+        }
+        Expr::Index(base, index) => {
+            let base = eval_expr(base, env, funcs)?;
+            let idx = eval_expr(index, env, funcs)?.as_i32()? as usize;
+            match base {
+                Value::Vec(items) => items
+                    .borrow()
+                    .get(idx)
+                    .cloned()
+                    .ok_or_else(|| format!("index {idx} out of bounds")),
+                Value::Str(s) => s
+                    .chars()
+                    .nth(idx)
+                    .map(Value::Char)
+                    .ok_or_else(|| format!("index {idx} out of bounds")),
+                other => Err(format!("cannot index into {other:?}")),
+            }
+        }
+        Expr::Range(lo, hi, inclusive) => {
+            let lo = eval_expr(lo, env, funcs)?.as_i32()?;
+            let hi = eval_expr(hi, env, funcs)?.as_i32()?;
+            let end = if *inclusive { hi + 1 } else { hi };
+            Ok(Value::Iter((lo..end).map(Value::I32).collect()))
+        }
+        Expr::Ref(inner) => eval_expr(inner, env, funcs),
+        Expr::Closure(..) => {
+            Err("closures can only be evaluated as arguments to iterator adaptors".to_string())
+        }
+        Expr::Match(scrutinee, arms) => {
+            let value = eval_expr(scrutinee, env, funcs)?;
+            for arm in arms {
+                if arm.patterns.iter().any(|p| pattern_matches(p, &value)) {
+                    return eval_expr(&arm.body, env, funcs);
+                }
+            }
+            Err("no match arm matched the scrutinee".to_string())
+        }
+    }
+}
+
+fn pattern_matches(pattern: &Pattern, value: &Value) -> bool {
+    match pattern {
+        Pattern::Wildcard => true,
+        Pattern::IntLit(n) => value.as_i32().ok() == Some(*n),
+        Pattern::StrLit(s) => value.as_str().ok() == Some(s.as_str()),
+        Pattern::CharLit(c) => value.as_char().ok() == Some(*c),
+        Pattern::BoolLit(b) => value.as_bool().ok() == Some(*b),
+        Pattern::Range(lo, hi) => pattern_range_contains(lo, hi, value),
+    }
+}
+
+fn pattern_range_contains(lo: &Pattern, hi: &Pattern, value: &Value) -> bool {
+    match (lo, hi) {
+        (Pattern::IntLit(a), Pattern::IntLit(b)) => {
+            value.as_i32().map(|v| v >= *a && v <= *b).unwrap_or(false)
+        }
+        (Pattern::StrLit(a), Pattern::StrLit(b)) => value
+            .as_str()
+            .map(|v| v >= a.as_str() && v <= b.as_str())
+            .unwrap_or(false),
+        (Pattern::CharLit(a), Pattern::CharLit(b)) => {
+            value.as_char().map(|v| v >= *a && v <= *b).unwrap_or(false)
+        }
+        _ => false,
+    }
+}
+
+fn eval_binary(op: BinOp, lhs: Value, rhs: Value) -> Result<Value, String> {
+    use BinOp::*;
+    match op {
+        Add => match (lhs, rhs) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a + b)),
+            (Value::Str(a), Value::Str(b)) => Ok(Value::Str(a + &b)),
+            (a, b) => Err(format!("cannot add {a:?} and {b:?}")),
+        },
+        Sub => Ok(Value::I32(lhs.as_i32()? - rhs.as_i32()?)),
+        Mul => Ok(Value::I32(lhs.as_i32()? * rhs.as_i32()?)),
+        Div => lhs
+            .as_i32()?
+            .checked_div(rhs.as_i32()?)
+            .map(Value::I32)
+            .ok_or_else(|| "attempt to divide by zero".to_string()),
+        Rem => lhs
+            .as_i32()?
+            .checked_rem(rhs.as_i32()?)
+            .map(Value::I32)
+            .ok_or_else(|| "attempt to calculate the remainder with a divisor of zero".to_string()),
+        Eq => Ok(Value::Bool(lhs == rhs)),
+        Ne => Ok(Value::Bool(lhs != rhs)),
+        Lt => Ok(Value::Bool(lhs.as_i32()? < rhs.as_i32()?)),
+        Le => Ok(Value::Bool(lhs.as_i32()? <= rhs.as_i32()?)),
+        Gt => Ok(Value::Bool(lhs.as_i32()? > rhs.as_i32()?)),
+        Ge => Ok(Value::Bool(lhs.as_i32()? >= rhs.as_i32()?)),
+    }
+}
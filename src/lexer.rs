@@ -0,0 +1,272 @@
+//! Hand-rolled lexer for the exercise language.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Ident(String),
+    Int(i32),
+    Str(String),
+    Char(char),
+
+    // keywords
+    Fn,
+    Let,
+    Mut,
+    If,
+    Else,
+    While,
+    For,
+    In,
+    Match,
+    Return,
+    Continue,
+    True,
+    False,
+
+    // punctuation
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Comma,
+    Semi,
+    Colon,
+    Arrow,
+    ColonColon,
+    Dot,
+    DotDot,
+    DotDotEq,
+    Amp,
+    Pipe,
+    FatArrow,
+    Underscore,
+
+    // operators
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    EqEq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    Eq,
+    AndAnd,
+    OrOr,
+    Bang,
+
+    Eof,
+}
+
+pub fn lex(source: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            ' ' | '\t' | '\r' | '\n' => i += 1,
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '0'..='9' => {
+                let start = i;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let n: i32 = text
+                    .parse()
+                    .map_err(|_| format!("invalid integer literal '{text}'"))?;
+                tokens.push(Token::Int(n));
+            }
+            '"' => {
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != '"' {
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err("unterminated string literal".to_string());
+                }
+                let text: String = chars[start..i].iter().collect();
+                i += 1;
+                tokens.push(Token::Str(text));
+            }
+            '\'' => {
+                i += 1;
+                if i >= chars.len() {
+                    return Err("unterminated char literal".to_string());
+                }
+                let ch = chars[i];
+                i += 1;
+                if chars.get(i) != Some(&'\'') {
+                    return Err("unterminated char literal".to_string());
+                }
+                i += 1;
+                tokens.push(Token::Char(ch));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                tokens.push(match text.as_str() {
+                    "fn" => Token::Fn,
+                    "let" => Token::Let,
+                    "mut" => Token::Mut,
+                    "if" => Token::If,
+                    "else" => Token::Else,
+                    "while" => Token::While,
+                    "for" => Token::For,
+                    "in" => Token::In,
+                    "match" => Token::Match,
+                    "return" => Token::Return,
+                    "continue" => Token::Continue,
+                    "true" => Token::True,
+                    "false" => Token::False,
+                    "_" => Token::Underscore,
+                    _ => Token::Ident(text),
+                });
+            }
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '{' => {
+                tokens.push(Token::LBrace);
+                i += 1;
+            }
+            '}' => {
+                tokens.push(Token::RBrace);
+                i += 1;
+            }
+            '[' => {
+                tokens.push(Token::LBracket);
+                i += 1;
+            }
+            ']' => {
+                tokens.push(Token::RBracket);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            ';' => {
+                tokens.push(Token::Semi);
+                i += 1;
+            }
+            ':' if chars.get(i + 1) == Some(&':') => {
+                tokens.push(Token::ColonColon);
+                i += 2;
+            }
+            ':' => {
+                tokens.push(Token::Colon);
+                i += 1;
+            }
+            '.' if chars.get(i + 1) == Some(&'.') && chars.get(i + 2) == Some(&'=') => {
+                tokens.push(Token::DotDotEq);
+                i += 3;
+            }
+            '.' if chars.get(i + 1) == Some(&'.') => {
+                tokens.push(Token::DotDot);
+                i += 2;
+            }
+            '.' => {
+                tokens.push(Token::Dot);
+                i += 1;
+            }
+            '&' if chars.get(i + 1) == Some(&'&') => {
+                tokens.push(Token::AndAnd);
+                i += 2;
+            }
+            '&' => {
+                tokens.push(Token::Amp);
+                i += 1;
+            }
+            '|' if chars.get(i + 1) == Some(&'|') => {
+                tokens.push(Token::OrOr);
+                i += 2;
+            }
+            '|' => {
+                tokens.push(Token::Pipe);
+                i += 1;
+            }
+            '-' if chars.get(i + 1) == Some(&'>') => {
+                tokens.push(Token::Arrow);
+                i += 2;
+            }
+            '+' => {
+                tokens.push(Token::Plus);
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Token::Minus);
+                i += 1;
+            }
+            '*' => {
+                tokens.push(Token::Star);
+                i += 1;
+            }
+            '/' => {
+                tokens.push(Token::Slash);
+                i += 1;
+            }
+            '%' => {
+                tokens.push(Token::Percent);
+                i += 1;
+            }
+            '=' if chars.get(i + 1) == Some(&'>') => {
+                tokens.push(Token::FatArrow);
+                i += 2;
+            }
+            '=' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::EqEq);
+                i += 2;
+            }
+            '=' => {
+                tokens.push(Token::Eq);
+                i += 1;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::NotEq);
+                i += 2;
+            }
+            '!' => {
+                tokens.push(Token::Bang);
+                i += 1;
+            }
+            '<' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::LtEq);
+                i += 2;
+            }
+            '<' => {
+                tokens.push(Token::Lt);
+                i += 1;
+            }
+            '>' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::GtEq);
+                i += 2;
+            }
+            '>' => {
+                tokens.push(Token::Gt);
+                i += 1;
+            }
+            other => return Err(format!("unexpected character '{other}'")),
+        }
+    }
+    tokens.push(Token::Eof);
+    Ok(tokens)
+}
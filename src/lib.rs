@@ -0,0 +1,20 @@
+//! A small interpreter for the toy language the `tests/unit/exercism`
+//! fixtures are written in.
+
+pub mod ast;
+pub mod interpreter;
+pub mod lexer;
+pub mod parser;
+pub mod types;
+pub mod value;
+
+use value::Value;
+
+/// Lexes, parses, type-checks, and evaluates a whole program, returning the
+/// value bound to its top-level `answer`.
+pub fn run(source: &str) -> Result<Value, String> {
+    let tokens = lexer::lex(source)?;
+    let program = parser::Parser::new(tokens).parse_program()?;
+    types::check_program(&program)?;
+    interpreter::run(&program)
+}
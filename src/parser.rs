@@ -0,0 +1,504 @@
+//! Recursive-descent parser: tokens -> [`Program`].
+
+use crate::ast::*;
+use crate::lexer::Token;
+
+pub struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<Token>) -> Self {
+        Parser { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn advance(&mut self) -> Token {
+        let tok = self.tokens[self.pos].clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<(), String> {
+        if self.peek() == expected {
+            self.advance();
+            Ok(())
+        } else {
+            Err(format!("expected {expected:?}, found {:?}", self.peek()))
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<String, String> {
+        match self.advance() {
+            Token::Ident(name) => Ok(name),
+            other => Err(format!("expected identifier, found {other:?}")),
+        }
+    }
+
+    pub fn parse_program(&mut self) -> Result<Program, String> {
+        let mut functions = Vec::new();
+        let mut top_level = Vec::new();
+        while self.peek() != &Token::Eof {
+            if self.peek() == &Token::Fn {
+                functions.push(self.parse_fn_decl()?);
+            } else {
+                top_level.push(self.parse_stmt()?);
+            }
+        }
+        Ok(Program {
+            functions,
+            top_level,
+        })
+    }
+
+    fn parse_fn_decl(&mut self) -> Result<FnDecl, String> {
+        self.expect(&Token::Fn)?;
+        let name = self.expect_ident()?;
+        self.expect(&Token::LParen)?;
+        let mut params = Vec::new();
+        while self.peek() != &Token::RParen {
+            let pname = self.expect_ident()?;
+            self.expect(&Token::Colon)?;
+            let ty = self.parse_type()?;
+            params.push(Param { name: pname, ty });
+            if self.peek() == &Token::Comma {
+                self.advance();
+            }
+        }
+        self.expect(&Token::RParen)?;
+        let ret = if self.peek() == &Token::Arrow {
+            self.advance();
+            self.parse_type()?
+        } else {
+            Type::Unit
+        };
+        let body = self.parse_block()?;
+        Ok(FnDecl {
+            name,
+            params,
+            ret,
+            body,
+        })
+    }
+
+    fn parse_type(&mut self) -> Result<Type, String> {
+        if self.peek() == &Token::Amp {
+            self.advance();
+            let name = self.expect_ident()?;
+            return match name.as_str() {
+                "str" => Ok(Type::Str),
+                other => Err(format!("unknown reference type '&{other}'")),
+            };
+        }
+        let name = self.expect_ident()?;
+        match name.as_str() {
+            "i32" => Ok(Type::I32),
+            "bool" => Ok(Type::Bool),
+            "char" => Ok(Type::Char),
+            "String" => Ok(Type::Str),
+            "Vec" => {
+                self.expect(&Token::Lt)?;
+                let inner = self.parse_type()?;
+                self.expect(&Token::Gt)?;
+                Ok(Type::Vec(Box::new(inner)))
+            }
+            other => Err(format!("unknown type '{other}'")),
+        }
+    }
+
+    fn parse_block(&mut self) -> Result<Vec<Stmt>, String> {
+        self.expect(&Token::LBrace)?;
+        let mut stmts = Vec::new();
+        while self.peek() != &Token::RBrace {
+            stmts.push(self.parse_stmt()?);
+        }
+        self.expect(&Token::RBrace)?;
+        Ok(stmts)
+    }
+
+    fn parse_stmt(&mut self) -> Result<Stmt, String> {
+        match self.peek() {
+            Token::Let => self.parse_let(),
+            Token::Return => self.parse_return(),
+            Token::If => self.parse_if(),
+            Token::While => self.parse_while(),
+            Token::For => self.parse_for(),
+            Token::Continue => {
+                self.advance();
+                self.expect(&Token::Semi)?;
+                Ok(Stmt::Continue)
+            }
+            _ => self.parse_assign_or_expr_stmt(),
+        }
+    }
+
+    fn parse_let(&mut self) -> Result<Stmt, String> {
+        self.expect(&Token::Let)?;
+        let mutable = if self.peek() == &Token::Mut {
+            self.advance();
+            true
+        } else {
+            false
+        };
+        let name = self.expect_ident()?;
+        let ty = if self.peek() == &Token::Colon {
+            self.advance();
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+        self.expect(&Token::Eq)?;
+        let value = self.parse_expr()?;
+        self.expect(&Token::Semi)?;
+        Ok(Stmt::Let {
+            name,
+            mutable,
+            ty,
+            value,
+        })
+    }
+
+    fn parse_return(&mut self) -> Result<Stmt, String> {
+        self.expect(&Token::Return)?;
+        let value = if self.peek() == &Token::Semi {
+            None
+        } else {
+            Some(self.parse_expr()?)
+        };
+        self.expect(&Token::Semi)?;
+        Ok(Stmt::Return(value))
+    }
+
+    fn parse_if(&mut self) -> Result<Stmt, String> {
+        self.expect(&Token::If)?;
+        let cond = self.parse_expr()?;
+        let then_branch = self.parse_block()?;
+        let else_branch = if self.peek() == &Token::Else {
+            self.advance();
+            if self.peek() == &Token::If {
+                Some(vec![self.parse_if()?])
+            } else {
+                Some(self.parse_block()?)
+            }
+        } else {
+            None
+        };
+        Ok(Stmt::If {
+            cond,
+            then_branch,
+            else_branch,
+        })
+    }
+
+    fn parse_while(&mut self) -> Result<Stmt, String> {
+        self.expect(&Token::While)?;
+        let cond = self.parse_expr()?;
+        let body = self.parse_block()?;
+        Ok(Stmt::While { cond, body })
+    }
+
+    fn parse_for(&mut self) -> Result<Stmt, String> {
+        self.expect(&Token::For)?;
+        let var = self.expect_ident()?;
+        self.expect(&Token::In)?;
+        let iter = self.parse_expr()?;
+        let body = self.parse_block()?;
+        Ok(Stmt::ForIn { var, iter, body })
+    }
+
+    fn parse_assign_or_expr_stmt(&mut self) -> Result<Stmt, String> {
+        let expr = self.parse_expr()?;
+        if self.peek() == &Token::Eq {
+            self.advance();
+            let target = expr_to_lvalue(expr)?;
+            let value = self.parse_expr()?;
+            self.expect(&Token::Semi)?;
+            Ok(Stmt::Assign { target, value })
+        } else {
+            self.expect(&Token::Semi)?;
+            Ok(Stmt::ExprStmt(expr))
+        }
+    }
+
+    pub fn parse_expr(&mut self) -> Result<Expr, String> {
+        self.parse_range()
+    }
+
+    fn parse_range(&mut self) -> Result<Expr, String> {
+        let lhs = self.parse_or()?;
+        match self.peek() {
+            Token::DotDot => {
+                self.advance();
+                let rhs = self.parse_or()?;
+                Ok(Expr::Range(Box::new(lhs), Box::new(rhs), false))
+            }
+            Token::DotDotEq => {
+                self.advance();
+                let rhs = self.parse_or()?;
+                Ok(Expr::Range(Box::new(lhs), Box::new(rhs), true))
+            }
+            _ => Ok(lhs),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.parse_and()?;
+        while self.peek() == &Token::OrOr {
+            self.advance();
+            let rhs = self.parse_and()?;
+            lhs = Expr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.parse_comparison()?;
+        while self.peek() == &Token::AndAnd {
+            self.advance();
+            let rhs = self.parse_comparison()?;
+            lhs = Expr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, String> {
+        let lhs = self.parse_additive()?;
+        let op = match self.peek() {
+            Token::EqEq => BinOp::Eq,
+            Token::NotEq => BinOp::Ne,
+            Token::Lt => BinOp::Lt,
+            Token::LtEq => BinOp::Le,
+            Token::Gt => BinOp::Gt,
+            Token::GtEq => BinOp::Ge,
+            _ => return Ok(lhs),
+        };
+        self.advance();
+        let rhs = self.parse_additive()?;
+        Ok(Expr::Binary(op, Box::new(lhs), Box::new(rhs)))
+    }
+
+    fn parse_additive(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.parse_term()?;
+        loop {
+            let op = match self.peek() {
+                Token::Plus => BinOp::Add,
+                Token::Minus => BinOp::Sub,
+                _ => break,
+            };
+            self.advance();
+            let rhs = self.parse_term()?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_term(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Token::Star => BinOp::Mul,
+                Token::Slash => BinOp::Div,
+                Token::Percent => BinOp::Rem,
+                _ => break,
+            };
+            self.advance();
+            let rhs = self.parse_unary()?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, String> {
+        if self.peek() == &Token::Minus {
+            self.advance();
+            let operand = self.parse_unary()?;
+            return Ok(Expr::Unary(UnOp::Neg, Box::new(operand)));
+        }
+        if self.peek() == &Token::Bang {
+            self.advance();
+            let operand = self.parse_unary()?;
+            return Ok(Expr::Unary(UnOp::Not, Box::new(operand)));
+        }
+        if self.peek() == &Token::Amp {
+            self.advance();
+            let operand = self.parse_unary()?;
+            return Ok(Expr::Ref(Box::new(operand)));
+        }
+        self.parse_postfix()
+    }
+
+    fn parse_postfix(&mut self) -> Result<Expr, String> {
+        let mut expr = self.parse_primary()?;
+        loop {
+            match self.peek() {
+                Token::LBracket => {
+                    self.advance();
+                    let index = self.parse_expr()?;
+                    self.expect(&Token::RBracket)?;
+                    expr = Expr::Index(Box::new(expr), Box::new(index));
+                }
+                Token::Dot => {
+                    self.advance();
+                    let method = self.expect_ident()?;
+                    let args = self.parse_call_args()?;
+                    expr = Expr::MethodCall(Box::new(expr), method, args);
+                }
+                _ => break,
+            }
+        }
+        Ok(expr)
+    }
+
+    fn parse_call_args(&mut self) -> Result<Vec<Expr>, String> {
+        self.expect(&Token::LParen)?;
+        let mut args = Vec::new();
+        while self.peek() != &Token::RParen {
+            args.push(self.parse_expr()?);
+            if self.peek() == &Token::Comma {
+                self.advance();
+            }
+        }
+        self.expect(&Token::RParen)?;
+        Ok(args)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, String> {
+        match self.advance() {
+            Token::Int(n) => Ok(Expr::IntLit(n)),
+            Token::Str(s) => Ok(Expr::StrLit(s)),
+            Token::Char(c) => Ok(Expr::CharLit(c)),
+            Token::True => Ok(Expr::BoolLit(true)),
+            Token::False => Ok(Expr::BoolLit(false)),
+            Token::Pipe => {
+                let mut params = Vec::new();
+                while self.peek() != &Token::Pipe {
+                    params.push(self.expect_ident()?);
+                    if self.peek() == &Token::Comma {
+                        self.advance();
+                    }
+                }
+                self.expect(&Token::Pipe)?;
+                let body = self.parse_expr()?;
+                Ok(Expr::Closure(params, Box::new(body)))
+            }
+            Token::Ident(name) => {
+                if self.peek() == &Token::ColonColon {
+                    self.advance();
+                    let method = self.expect_ident()?;
+                    let args = self.parse_call_args()?;
+                    Ok(Expr::PathCall(name, method, args))
+                } else if self.peek() == &Token::LParen {
+                    let args = self.parse_call_args()?;
+                    Ok(Expr::Call(name, args))
+                } else {
+                    Ok(Expr::Ident(name))
+                }
+            }
+            Token::LParen => {
+                let expr = self.parse_expr()?;
+                self.expect(&Token::RParen)?;
+                Ok(expr)
+            }
+            Token::Match => {
+                let scrutinee = self.parse_expr()?;
+                self.expect(&Token::LBrace)?;
+                let mut arms = Vec::new();
+                while self.peek() != &Token::RBrace {
+                    let patterns = self.parse_match_arm_patterns()?;
+                    self.expect(&Token::FatArrow)?;
+                    let body = self.parse_expr()?;
+                    arms.push(MatchArm { patterns, body });
+                    if self.peek() == &Token::Comma {
+                        self.advance();
+                    }
+                }
+                self.expect(&Token::RBrace)?;
+                Ok(Expr::Match(Box::new(scrutinee), arms))
+            }
+            other => Err(format!("unexpected token {other:?}")),
+        }
+    }
+
+    fn parse_match_arm_patterns(&mut self) -> Result<Vec<Pattern>, String> {
+        let mut patterns = vec![self.parse_pattern_atom()?];
+        while self.peek() == &Token::Pipe {
+            self.advance();
+            patterns.push(self.parse_pattern_atom()?);
+        }
+        Ok(patterns)
+    }
+
+    fn parse_pattern_atom(&mut self) -> Result<Pattern, String> {
+        match self.advance() {
+            Token::Underscore => Ok(Pattern::Wildcard),
+            Token::True => Ok(Pattern::BoolLit(true)),
+            Token::False => Ok(Pattern::BoolLit(false)),
+            Token::Int(n) => {
+                if self.peek() == &Token::DotDotEq {
+                    self.advance();
+                    let hi = match self.advance() {
+                        Token::Int(h) => h,
+                        other => {
+                            return Err(format!("expected integer pattern bound, found {other:?}"))
+                        }
+                    };
+                    Ok(Pattern::Range(
+                        Box::new(Pattern::IntLit(n)),
+                        Box::new(Pattern::IntLit(hi)),
+                    ))
+                } else {
+                    Ok(Pattern::IntLit(n))
+                }
+            }
+            Token::Str(s) => {
+                if self.peek() == &Token::DotDotEq {
+                    self.advance();
+                    let hi = match self.advance() {
+                        Token::Str(h) => h,
+                        other => {
+                            return Err(format!("expected string pattern bound, found {other:?}"))
+                        }
+                    };
+                    Ok(Pattern::Range(
+                        Box::new(Pattern::StrLit(s)),
+                        Box::new(Pattern::StrLit(hi)),
+                    ))
+                } else {
+                    Ok(Pattern::StrLit(s))
+                }
+            }
+            Token::Char(c) => {
+                if self.peek() == &Token::DotDotEq {
+                    self.advance();
+                    let hi = match self.advance() {
+                        Token::Char(h) => h,
+                        other => {
+                            return Err(format!("expected char pattern bound, found {other:?}"))
+                        }
+                    };
+                    Ok(Pattern::Range(
+                        Box::new(Pattern::CharLit(c)),
+                        Box::new(Pattern::CharLit(hi)),
+                    ))
+                } else {
+                    Ok(Pattern::CharLit(c))
+                }
+            }
+            other => Err(format!("invalid match pattern: unexpected token {other:?}")),
+        }
+    }
+}
+
+fn expr_to_lvalue(expr: Expr) -> Result<LValue, String> {
+    match expr {
+        Expr::Ident(name) => Ok(LValue::Ident(name)),
+        Expr::Index(base, index) => Ok(LValue::Index(Box::new(expr_to_lvalue(*base)?), *index)),
+        other => Err(format!("invalid assignment target {other:?}")),
+    }
+}
@@ -0,0 +1,514 @@
+//! Static type checker.
+
+use crate::ast::*;
+use std::collections::HashMap;
+
+struct FnSig {
+    params: Vec<Type>,
+    ret: Type,
+}
+
+struct Scope {
+    vars: HashMap<String, (Type, bool)>,
+}
+
+impl Scope {
+    fn new() -> Self {
+        Scope {
+            vars: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, name: &str, ty: Type, mutable: bool) {
+        self.vars.insert(name.to_string(), (ty, mutable));
+    }
+
+    fn get(&self, name: &str) -> Result<(Type, bool), String> {
+        self.vars
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("use of undeclared variable '{name}'"))
+    }
+}
+
+pub fn check_program(program: &Program) -> Result<(), String> {
+    let mut funcs = HashMap::new();
+    for f in &program.functions {
+        funcs.insert(
+            f.name.clone(),
+            FnSig {
+                params: f.params.iter().map(|p| p.ty.clone()).collect(),
+                ret: f.ret.clone(),
+            },
+        );
+    }
+    for f in &program.functions {
+        let mut scope = Scope::new();
+        for p in &f.params {
+            scope.insert(&p.name, p.ty.clone(), false);
+        }
+        check_block(&f.body, &mut scope, &funcs, Some(&f.ret))?;
+    }
+    let mut scope = Scope::new();
+    check_block(&program.top_level, &mut scope, &funcs, None)?;
+    Ok(())
+}
+
+fn check_block(
+    stmts: &[Stmt],
+    scope: &mut Scope,
+    funcs: &HashMap<String, FnSig>,
+    ret_ty: Option<&Type>,
+) -> Result<(), String> {
+    for stmt in stmts {
+        check_stmt(stmt, scope, funcs, ret_ty)?;
+    }
+    Ok(())
+}
+
+fn check_stmt(
+    stmt: &Stmt,
+    scope: &mut Scope,
+    funcs: &HashMap<String, FnSig>,
+    ret_ty: Option<&Type>,
+) -> Result<(), String> {
+    match stmt {
+        Stmt::Let {
+            name,
+            mutable,
+            ty,
+            value,
+        } => {
+            let value_ty = check_expr(value, scope, funcs)?;
+            if let Some(declared) = ty {
+                if declared != &value_ty {
+                    return Err(format!(
+                        "let '{name}' declared as {declared:?} but initialized with {value_ty:?}"
+                    ));
+                }
+            }
+            scope.insert(name, value_ty, *mutable);
+            Ok(())
+        }
+        Stmt::Assign { target, value } => {
+            let target_ty = check_lvalue(target, scope, funcs)?;
+            if !lvalue_root_mutable(target, scope)? {
+                return Err(format!(
+                    "cannot assign to '{}': not declared `mut`",
+                    lvalue_root_name(target)
+                ));
+            }
+            let value_ty = check_expr(value, scope, funcs)?;
+            if target_ty != value_ty {
+                return Err(format!(
+                    "cannot assign {value_ty:?} to target of type {target_ty:?}"
+                ));
+            }
+            Ok(())
+        }
+        Stmt::ExprStmt(expr) => {
+            check_expr(expr, scope, funcs)?;
+            Ok(())
+        }
+        Stmt::Return(value) => {
+            let ret_ty = ret_ty.ok_or_else(|| "return is not allowed here".to_string())?;
+            match value {
+                Some(expr) => {
+                    let ty = check_expr(expr, scope, funcs)?;
+                    if &ty != ret_ty {
+                        return Err(format!(
+                            "function returns {ret_ty:?} but 'return' produced {ty:?}"
+                        ));
+                    }
+                }
+                None => {
+                    if ret_ty != &Type::Unit {
+                        return Err(format!("function returns {ret_ty:?} but 'return;' has no value"));
+                    }
+                }
+            }
+            Ok(())
+        }
+        Stmt::If {
+            cond,
+            then_branch,
+            else_branch,
+        } => {
+            require_bool(cond, scope, funcs)?;
+            check_block(then_branch, scope, funcs, ret_ty)?;
+            if let Some(else_branch) = else_branch {
+                check_block(else_branch, scope, funcs, ret_ty)?;
+            }
+            Ok(())
+        }
+        Stmt::While { cond, body } => {
+            require_bool(cond, scope, funcs)?;
+            check_block(body, scope, funcs, ret_ty)?;
+            Ok(())
+        }
+        Stmt::ForIn { var, iter, body } => {
+            let iter_ty = check_expr(iter, scope, funcs)?;
+            let elem_ty = match iter_ty {
+                Type::Iter(elem) => *elem,
+                Type::Vec(elem) => *elem,
+                Type::Str => Type::Char,
+                other => return Err(format!("cannot iterate over {other:?}")),
+            };
+            scope.insert(var, elem_ty, false);
+            check_block(body, scope, funcs, ret_ty)?;
+            Ok(())
+        }
+        Stmt::Continue => Ok(()),
+    }
+}
+
+fn require_bool(expr: &Expr, scope: &Scope, funcs: &HashMap<String, FnSig>) -> Result<(), String> {
+    let ty = check_expr(expr, scope, funcs)?;
+    if ty == Type::Bool {
+        Ok(())
+    } else {
+        Err(format!("condition must be bool, found {ty:?}"))
+    }
+}
+
+fn check_lvalue(
+    target: &LValue,
+    scope: &Scope,
+    funcs: &HashMap<String, FnSig>,
+) -> Result<Type, String> {
+    match target {
+        LValue::Ident(name) => Ok(scope.get(name)?.0),
+        LValue::Index(base, index) => {
+            let base_ty = check_lvalue(base, scope, funcs)?;
+            let index_ty = check_expr(index, scope, funcs)?;
+            if index_ty != Type::I32 {
+                return Err(format!("index must be i32, found {index_ty:?}"));
+            }
+            match base_ty {
+                Type::Vec(inner) => Ok(*inner),
+                other => Err(format!("cannot index into {other:?}")),
+            }
+        }
+    }
+}
+
+fn lvalue_root_mutable(target: &LValue, scope: &Scope) -> Result<bool, String> {
+    match target {
+        LValue::Ident(name) => Ok(scope.get(name)?.1),
+        LValue::Index(base, _) => lvalue_root_mutable(base, scope),
+    }
+}
+
+fn lvalue_root_name(target: &LValue) -> &str {
+    match target {
+        LValue::Ident(name) => name,
+        LValue::Index(base, _) => lvalue_root_name(base),
+    }
+}
+
+fn check_expr(expr: &Expr, scope: &Scope, funcs: &HashMap<String, FnSig>) -> Result<Type, String> {
+    match expr {
+        Expr::IntLit(_) => Ok(Type::I32),
+        Expr::BoolLit(_) => Ok(Type::Bool),
+        Expr::StrLit(_) => Ok(Type::Str),
+        Expr::CharLit(_) => Ok(Type::Char),
+        Expr::Ident(name) => Ok(scope.get(name)?.0),
+        Expr::Unary(UnOp::Neg, inner) => {
+            let ty = check_expr(inner, scope, funcs)?;
+            if ty != Type::I32 {
+                return Err(format!("unary '-' requires i32, found {ty:?}"));
+            }
+            Ok(Type::I32)
+        }
+        Expr::Unary(UnOp::Not, inner) => {
+            let ty = check_expr(inner, scope, funcs)?;
+            if ty != Type::Bool {
+                return Err(format!("unary '!' requires bool, found {ty:?}"));
+            }
+            Ok(Type::Bool)
+        }
+        Expr::Binary(op, lhs, rhs) => {
+            let lhs_ty = check_expr(lhs, scope, funcs)?;
+            let rhs_ty = check_expr(rhs, scope, funcs)?;
+            check_binary(*op, &lhs_ty, &rhs_ty)
+        }
+        Expr::And(lhs, rhs) | Expr::Or(lhs, rhs) => {
+            let lhs_ty = check_expr(lhs, scope, funcs)?;
+            let rhs_ty = check_expr(rhs, scope, funcs)?;
+            if lhs_ty != Type::Bool || rhs_ty != Type::Bool {
+                return Err(format!(
+                    "'&&'/'||' require bool operands, found {lhs_ty:?} and {rhs_ty:?}"
+                ));
+            }
+            Ok(Type::Bool)
+        }
+        Expr::Call(name, args) => {
+            let sig = funcs
+                .get(name)
+                .ok_or_else(|| format!("call to undeclared function '{name}'"))?;
+            if args.len() != sig.params.len() {
+                return Err(format!(
+                    "'{name}' expects {} argument(s), found {}",
+                    sig.params.len(),
+                    args.len()
+                ));
+            }
+            for (arg, expected) in args.iter().zip(&sig.params) {
+                let ty = check_expr(arg, scope, funcs)?;
+                if &ty != expected {
+                    return Err(format!(
+                        "'{name}' expects argument of type {expected:?}, found {ty:?}"
+                    ));
+                }
+            }
+            Ok(sig.ret.clone())
+        }
+        Expr::PathCall(ty_name, method, args) => match (ty_name.as_str(), method.as_str()) {
+            ("Vec", "filled") => {
+                if args.len() != 2 {
+                    return Err("Vec::filled expects 2 arguments (len, value)".to_string());
+                }
+                let len_ty = check_expr(&args[0], scope, funcs)?;
+                if len_ty != Type::I32 {
+                    return Err(format!("Vec::filled length must be i32, found {len_ty:?}"));
+                }
+                let elem_ty = check_expr(&args[1], scope, funcs)?;
+                Ok(Type::Vec(Box::new(elem_ty)))
+            }
+            (ty_name, method) => Err(format!("unknown associated function '{ty_name}::{method}'")),
+        },
+        Expr::MethodCall(recv, method, args) => {
+            let recv_ty = check_expr(recv, scope, funcs)?;
+            match (&recv_ty, method.as_str()) {
+                (Type::Vec(_), "len") => {
+                    if !args.is_empty() {
+                        return Err("Vec::len takes no arguments".to_string());
+                    }
+                    Ok(Type::I32)
+                }
+                (Type::Str, "rev") => {
+                    if !args.is_empty() {
+                        return Err("str::rev takes no arguments".to_string());
+                    }
+                    Ok(Type::Iter(Box::new(Type::Char)))
+                }
+                (Type::Iter(elem), "rev") => {
+                    if !args.is_empty() {
+                        return Err("Iter::rev takes no arguments".to_string());
+                    }
+                    Ok(Type::Iter(elem.clone()))
+                }
+                (Type::Iter(elem), "take") => {
+                    if args.len() != 1 {
+                        return Err("Iter::take expects 1 argument".to_string());
+                    }
+                    let n_ty = check_expr(&args[0], scope, funcs)?;
+                    if n_ty != Type::I32 {
+                        return Err(format!("Iter::take expects an i32 argument, found {n_ty:?}"));
+                    }
+                    Ok(Type::Iter(elem.clone()))
+                }
+                (Type::Iter(elem), "map") => {
+                    if args.len() != 1 {
+                        return Err("Iter::map expects 1 argument".to_string());
+                    }
+                    let (params, body) = expect_closure(&args[0])?;
+                    if params.len() != 1 {
+                        return Err(
+                            "closure passed to 'map' must take exactly 1 parameter".to_string()
+                        );
+                    }
+                    let mut closure_scope = Scope::new();
+                    closure_scope.insert(&params[0], (**elem).clone(), false);
+                    let out_ty = check_expr(body, &closure_scope, funcs)?;
+                    Ok(Type::Iter(Box::new(out_ty)))
+                }
+                (Type::Iter(elem), "filter") => {
+                    if args.len() != 1 {
+                        return Err("Iter::filter expects 1 argument".to_string());
+                    }
+                    let (params, body) = expect_closure(&args[0])?;
+                    if params.len() != 1 {
+                        return Err(
+                            "closure passed to 'filter' must take exactly 1 parameter".to_string()
+                        );
+                    }
+                    let mut closure_scope = Scope::new();
+                    closure_scope.insert(&params[0], (**elem).clone(), false);
+                    let out_ty = check_expr(body, &closure_scope, funcs)?;
+                    if out_ty != Type::Bool {
+                        return Err(format!(
+                            "closure passed to 'filter' must return bool, found {out_ty:?}"
+                        ));
+                    }
+                    Ok(Type::Iter(elem.clone()))
+                }
+                (Type::Iter(elem), "sum") => {
+                    if !args.is_empty() {
+                        return Err("Iter::sum takes no arguments".to_string());
+                    }
+                    if **elem != Type::I32 {
+                        return Err(format!("Iter::sum requires i32 elements, found {elem:?}"));
+                    }
+                    Ok(Type::I32)
+                }
+                (Type::Iter(_), "count") => {
+                    if !args.is_empty() {
+                        return Err("Iter::count takes no arguments".to_string());
+                    }
+                    Ok(Type::I32)
+                }
+                (Type::Char, "is_whitespace" | "is_uppercase" | "is_lowercase" | "is_alphabetic") => {
+                    if !args.is_empty() {
+                        return Err(format!("char::{method} takes no arguments"));
+                    }
+                    Ok(Type::Bool)
+                }
+                (Type::Char, "to_lowercase") => {
+                    if !args.is_empty() {
+                        return Err("char::to_lowercase takes no arguments".to_string());
+                    }
+                    Ok(Type::Char)
+                }
+                (Type::Char, "to_ascii_digit") => {
+                    if !args.is_empty() {
+                        return Err("char::to_ascii_digit takes no arguments".to_string());
+                    }
+                    Ok(Type::I32)
+                }
+                (ty, method) => Err(format!("no method '{method}' on type {ty:?}")),
+            }
+        }
+        Expr::Index(base, index) => {
+            let base_ty = check_expr(base, scope, funcs)?;
+            let index_ty = check_expr(index, scope, funcs)?;
+            if index_ty != Type::I32 {
+                return Err(format!("index must be i32, found {index_ty:?}"));
+            }
+            match base_ty {
+                Type::Vec(inner) => Ok(*inner),
+                Type::Str => Ok(Type::Char),
+                other => Err(format!("cannot index into {other:?}")),
+            }
+        }
+        Expr::Range(lo, hi, _inclusive) => {
+            let lo_ty = check_expr(lo, scope, funcs)?;
+            let hi_ty = check_expr(hi, scope, funcs)?;
+            if lo_ty != Type::I32 || hi_ty != Type::I32 {
+                return Err(format!(
+                    "range bounds must be i32, found {lo_ty:?} and {hi_ty:?}"
+                ));
+            }
+            Ok(Type::Iter(Box::new(Type::I32)))
+        }
+        Expr::Ref(inner) => check_expr(inner, scope, funcs),
+        Expr::Closure(..) => {
+            Err("closures may only appear as arguments to iterator adaptors like 'map'/'filter'".to_string())
+        }
+        Expr::Match(scrutinee, arms) => {
+            let scrutinee_ty = check_expr(scrutinee, scope, funcs)?;
+            if arms.is_empty() {
+                return Err("match must have at least one arm".to_string());
+            }
+            let mut has_wildcard = false;
+            let mut result_ty: Option<Type> = None;
+            for arm in arms {
+                for pattern in &arm.patterns {
+                    check_pattern(pattern, &scrutinee_ty)?;
+                    if matches!(pattern, Pattern::Wildcard) {
+                        has_wildcard = true;
+                    }
+                }
+                let body_ty = check_expr(&arm.body, scope, funcs)?;
+                match &result_ty {
+                    Some(ty) if ty != &body_ty => {
+                        return Err(format!(
+                            "match arms have incompatible types: {ty:?} and {body_ty:?}"
+                        ));
+                    }
+                    _ => result_ty = Some(body_ty),
+                }
+            }
+            if !has_wildcard {
+                return Err("match must have a wildcard '_' arm".to_string());
+            }
+            Ok(result_ty.unwrap())
+        }
+    }
+}
+
+fn check_pattern(pattern: &Pattern, scrutinee_ty: &Type) -> Result<(), String> {
+    match pattern {
+        Pattern::Wildcard => Ok(()),
+        Pattern::IntLit(_) => {
+            if scrutinee_ty != &Type::I32 {
+                return Err(format!("expected {scrutinee_ty:?} pattern, found an i32 literal"));
+            }
+            Ok(())
+        }
+        Pattern::StrLit(_) => {
+            if scrutinee_ty != &Type::Str {
+                return Err(format!("expected {scrutinee_ty:?} pattern, found a str literal"));
+            }
+            Ok(())
+        }
+        Pattern::CharLit(_) => {
+            if scrutinee_ty != &Type::Char {
+                return Err(format!("expected {scrutinee_ty:?} pattern, found a char literal"));
+            }
+            Ok(())
+        }
+        Pattern::BoolLit(_) => {
+            if scrutinee_ty != &Type::Bool {
+                return Err(format!("expected {scrutinee_ty:?} pattern, found a bool literal"));
+            }
+            Ok(())
+        }
+        Pattern::Range(lo, hi) => {
+            check_pattern(lo, scrutinee_ty)?;
+            check_pattern(hi, scrutinee_ty)?;
+            Ok(())
+        }
+    }
+}
+
+fn expect_closure(expr: &Expr) -> Result<(&[String], &Expr), String> {
+    match expr {
+        Expr::Closure(params, body) => Ok((params, body)),
+        other => Err(format!("expected a closure argument, found {other:?}")),
+    }
+}
+
+fn check_binary(op: BinOp, lhs: &Type, rhs: &Type) -> Result<Type, String> {
+    use BinOp::*;
+    match op {
+        Add => match (lhs, rhs) {
+            (Type::I32, Type::I32) => Ok(Type::I32),
+            (Type::Str, Type::Str) => Ok(Type::Str),
+            _ => Err(format!("cannot add {lhs:?} and {rhs:?}")),
+        },
+        Sub | Mul | Div | Rem => {
+            if lhs == &Type::I32 && rhs == &Type::I32 {
+                Ok(Type::I32)
+            } else {
+                Err(format!("arithmetic requires i32 operands, found {lhs:?} and {rhs:?}"))
+            }
+        }
+        Eq | Ne => {
+            if matches!(lhs, Type::Vec(_) | Type::Iter(_)) || matches!(rhs, Type::Vec(_) | Type::Iter(_)) {
+                Err(format!("cannot compare {lhs:?} with {rhs:?}: Vec/Iter have no notion of equality"))
+            } else if lhs == rhs {
+                Ok(Type::Bool)
+            } else {
+                Err(format!("cannot compare {lhs:?} with {rhs:?}"))
+            }
+        }
+        Lt | Le | Gt | Ge => {
+            if lhs == &Type::I32 && rhs == &Type::I32 {
+                Ok(Type::Bool)
+            } else {
+                Err(format!("ordering comparison requires i32 operands, found {lhs:?} and {rhs:?}"))
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Runtime values.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    I32(i32),
+    Str(String),
+    Char(char),
+    Bool(bool),
+    Vec(Rc<RefCell<Vec<Value>>>),
+    /// An iterator pipeline, e.g. the result of `1..=n` or `.map(...)`.
+    ///
+    /// Deliberately eager, not lazy: each adaptor in `interpreter.rs`
+    /// (`rev`/`take`/`map`/`filter`) consumes its input and allocates a new
+    /// `Vec` rather than wrapping it in a deferred combinator. That's a
+    /// simplification accepted for this toy language's exercise-sized
+    /// inputs, not an oversight.
+    Iter(Vec<Value>),
+}
+
+impl Value {
+    pub fn as_i32(&self) -> Result<i32, String> {
+        match self {
+            Value::I32(n) => Ok(*n),
+            other => Err(format!("expected i32, found {other:?}")),
+        }
+    }
+
+    pub fn as_bool(&self) -> Result<bool, String> {
+        match self {
+            Value::Bool(b) => Ok(*b),
+            other => Err(format!("expected bool, found {other:?}")),
+        }
+    }
+
+    pub fn as_str(&self) -> Result<&str, String> {
+        match self {
+            Value::Str(s) => Ok(s),
+            other => Err(format!("expected str, found {other:?}")),
+        }
+    }
+
+    pub fn as_char(&self) -> Result<char, String> {
+        match self {
+            Value::Char(c) => Ok(*c),
+            other => Err(format!("expected char, found {other:?}")),
+        }
+    }
+
+    pub fn as_vec(&self) -> Result<Rc<RefCell<Vec<Value>>>, String> {
+        match self {
+            Value::Vec(v) => Ok(v.clone()),
+            other => Err(format!("expected Vec, found {other:?}")),
+        }
+    }
+
+    pub fn into_iter_items(self) -> Result<Vec<Value>, String> {
+        match self {
+            Value::Iter(items) => Ok(items),
+            Value::Str(s) => Ok(s.chars().map(Value::Char).collect()),
+            other => Err(format!("expected an iterator, found {other:?}")),
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => a == b,
+            (Value::Str(a), Value::Str(b)) => a == b,
+            (Value::Char(a), Value::Char(b)) => a == b,
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            _ => false,
+        }
+    }
+}
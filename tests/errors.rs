@@ -0,0 +1,42 @@
+//! Regression tests for runtime/type-check error paths that have no
+//! "happy path" fixture to exercise them.
+
+#[test]
+fn out_of_bounds_indexed_write_is_an_error() {
+    let source = "
+        let mut v: Vec<i32> = Vec::filled(3, 0);
+        v[10] = 5;
+        let answer = v[0];
+    ";
+    assert!(red_dragon::run(source).is_err());
+}
+
+#[test]
+fn divide_by_zero_is_an_error() {
+    let source = "
+        let x: i32 = 5;
+        let y: i32 = 0;
+        let answer = x / y;
+    ";
+    assert!(red_dragon::run(source).is_err());
+}
+
+#[test]
+fn rem_by_zero_is_an_error() {
+    let source = "
+        let x: i32 = 5;
+        let y: i32 = 0;
+        let answer = x % y;
+    ";
+    assert!(red_dragon::run(source).is_err());
+}
+
+#[test]
+fn vec_equality_is_a_type_error() {
+    let source = "
+        let a: Vec<i32> = Vec::filled(3, 0);
+        let b: Vec<i32> = Vec::filled(3, 0);
+        let answer = a == b;
+    ";
+    assert!(red_dragon::run(source).is_err());
+}
@@ -0,0 +1,101 @@
+//! Runs the exercism fixtures under `tests/unit/exercism` through the
+//! interpreter and checks the value each one binds to `answer`.
+
+use std::path::Path;
+
+fn run(rel: &str) -> red_dragon::value::Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(rel);
+    let source =
+        std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("failed to read {path:?}: {e}"));
+    red_dragon::run(&source).unwrap_or_else(|e| panic!("{rel} failed: {e}"))
+}
+
+#[test]
+fn wildcard_matching_answer() {
+    let v = run("tests/unit/exercism/exercises/wildcard_matching/solutions/rust.rs");
+    assert!(v.as_bool().unwrap());
+}
+
+#[test]
+fn collatz_conjecture_answer() {
+    let v = run("tests/unit/exercism/exercises/collatz_conjecture/solutions/rust.rs");
+    assert_eq!(v.as_i32().unwrap(), 4);
+}
+
+#[test]
+fn grains_answer() {
+    let v = run("tests/unit/exercism/exercises/grains/solutions/rust.rs");
+    assert_eq!(v.as_i32().unwrap(), 1);
+}
+
+#[test]
+fn nth_prime_answer() {
+    let v = run("tests/unit/exercism/exercises/nth_prime/solutions/rust.rs");
+    assert_eq!(v.as_i32().unwrap(), 2);
+}
+
+#[test]
+fn rna_transcription_answer() {
+    let v = run("tests/unit/exercism/exercises/rna_transcription/solutions/rust.rs");
+    assert_eq!(v.as_str().unwrap(), "UGCACCAGAAUU");
+}
+
+#[test]
+fn triangle_answer() {
+    let v = run("tests/unit/exercism/exercises/triangle/solutions/rust.rs");
+    assert!(v.as_bool().unwrap());
+}
+
+#[test]
+fn leap_answer() {
+    let v = run("tests/unit/exercism/exercises/leap/solutions/rust.rs");
+    assert!(v.as_bool().unwrap());
+}
+
+#[test]
+fn difference_of_squares_answer() {
+    let v = run("tests/unit/exercism/exercises/difference_of_squares/solutions/rust.rs");
+    assert_eq!(v.as_i32().unwrap(), 2640);
+}
+
+#[test]
+fn hamming_answer() {
+    let v = run("tests/unit/exercism/exercises/hamming/solutions/rust.rs");
+    assert_eq!(v.as_i32().unwrap(), 7);
+}
+
+#[test]
+fn bob_answer() {
+    let v = run("tests/unit/exercism/exercises/bob/solutions/rust.rs");
+    assert_eq!(v.as_str().unwrap(), "Whatever.");
+}
+
+#[test]
+fn pangram_answer() {
+    let v = run("tests/unit/exercism/exercises/pangram/solutions/rust.rs");
+    assert!(v.as_bool().unwrap());
+}
+
+#[test]
+fn luhn_answer() {
+    let v = run("tests/unit/exercism/exercises/luhn/solutions/rust.rs");
+    assert!(v.as_bool().unwrap());
+}
+
+#[test]
+fn pangram_unicode_answer() {
+    let v = run("tests/unit/exercism/exercises/pangram_unicode/solutions/rust.rs");
+    assert!(v.as_bool().unwrap());
+}
+
+#[test]
+fn scrabble_score_answer() {
+    let v = run("tests/unit/exercism/exercises/scrabble_score/solutions/rust.rs");
+    assert_eq!(v.as_i32().unwrap(), 14);
+}
+
+#[test]
+fn exam_grade_answer() {
+    let v = run("tests/unit/exercism/exercises/exam_grade/solutions/rust.rs");
+    assert!(v.as_bool().unwrap());
+}
@@ -1,21 +1,10 @@
 fn square_of_sum(n: i32) -> i32 {
-    let mut total: i32 = 0;
-    let mut i: i32 = 1;
-    while i <= n {
-        total = total + i;
-        i = i + 1;
-    }
+    let total: i32 = (1..=n).sum();
     return total * total;
 }
 
 fn sum_of_squares(n: i32) -> i32 {
-    let mut total: i32 = 0;
-    let mut i: i32 = 1;
-    while i <= n {
-        total = total + i * i;
-        i = i + 1;
-    }
-    return total;
+    return (1..=n).map(|i| i * i).sum();
 }
 
 fn difference_of_squares(n: i32) -> i32 {
@@ -0,0 +1,18 @@
+fn letter_grade(score: i32) -> &str {
+    return match score {
+        90..=100 => "A",
+        80..=89 => "B",
+        70..=79 => "C",
+        60..=69 => "D",
+        _ => "F",
+    };
+}
+
+fn passed(grade: &str) -> bool {
+    return match grade {
+        "A" | "B" | "C" | "D" => true,
+        _ => false,
+    };
+}
+
+let answer = passed(letter_grade(85));
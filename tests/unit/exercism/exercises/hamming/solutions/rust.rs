@@ -1,11 +1,9 @@
 fn hamming_distance(s1: &str, s2: &str, n: i32) -> i32 {
-    let distance = 0;
-    let i = 0;
-    while i < n {
+    let mut distance: i32 = 0;
+    for i in 0..n {
         if s1[i] != s2[i] {
             distance = distance + 1;
         }
-        i = i + 1;
     }
     return distance;
 }
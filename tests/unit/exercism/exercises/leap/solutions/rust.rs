@@ -1,14 +1,5 @@
-fn leap_year(year: i32) -> i32 {
-    if year % 400 == 0 {
-        return 1;
-    }
-    if year % 100 == 0 {
-        return 0;
-    }
-    if year % 4 == 0 {
-        return 1;
-    }
-    return 0;
+fn leap_year(year: i32) -> bool {
+    return year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
 }
 
 let answer = leap_year(2000);
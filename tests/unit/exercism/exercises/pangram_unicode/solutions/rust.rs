@@ -0,0 +1,17 @@
+fn is_pangram(sentence: &str) -> bool {
+    let letters: &str = "abcdefghijklmnopqrstuvwxyz";
+    for letter in letters {
+        let mut found: bool = false;
+        for c in sentence {
+            if c.to_lowercase() == letter {
+                found = true;
+            }
+        }
+        if !found {
+            return false;
+        }
+    }
+    return true;
+}
+
+let answer = is_pangram("Île de Ré: abcdefghijklmnopqrstuvwxyz");
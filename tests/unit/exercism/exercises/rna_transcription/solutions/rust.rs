@@ -1,19 +1,14 @@
 fn to_rna(dna: &str, n: i32) -> String {
-    let result = "";
-    let i = 0;
+    let mut result = "";
+    let mut i = 0;
     while i < n {
-        if dna[i] == "G" {
-            result = result + "C";
-        }
-        if dna[i] == "C" {
-            result = result + "G";
-        }
-        if dna[i] == "T" {
-            result = result + "A";
-        }
-        if dna[i] == "A" {
-            result = result + "U";
-        }
+        result = result + match dna[i] {
+            'G' => "C",
+            'C' => "G",
+            'T' => "A",
+            'A' => "U",
+            _ => "",
+        };
         i = i + 1;
     }
     return result;
@@ -0,0 +1,22 @@
+fn letter_score(c: char) -> i32 {
+    return match c {
+        'a' | 'e' | 'i' | 'o' | 'u' | 'l' | 'n' | 'r' | 's' | 't' => 1,
+        'd' | 'g' => 2,
+        'b' | 'c' | 'm' | 'p' => 3,
+        'f' | 'h' | 'v' | 'w' | 'y' => 4,
+        'k' => 5,
+        'j' | 'x' => 8,
+        'q' | 'z' => 10,
+        _ => 0,
+    };
+}
+
+fn score(word: &str) -> i32 {
+    let mut total: i32 = 0;
+    for c in word {
+        total = total + letter_score(c);
+    }
+    return total;
+}
+
+let answer = score("cabbage");
@@ -1,90 +1,17 @@
-fn is_equilateral(a: i32, b: i32, c: i32) -> i32 {
-    if a <= 0 {
-        return 0;
-    }
-    if b <= 0 {
-        return 0;
-    }
-    if c <= 0 {
-        return 0;
-    }
-    if a + b <= c {
-        return 0;
-    }
-    if b + c <= a {
-        return 0;
-    }
-    if a + c <= b {
-        return 0;
-    }
-    if a == b {
-        if b == c {
-            return 1;
-        }
-    }
-    return 0;
+fn is_triangle(a: i32, b: i32, c: i32) -> bool {
+    return a > 0 && b > 0 && c > 0 && a + b > c && b + c > a && a + c > b;
 }
 
-fn is_isosceles(a: i32, b: i32, c: i32) -> i32 {
-    if a <= 0 {
-        return 0;
-    }
-    if b <= 0 {
-        return 0;
-    }
-    if c <= 0 {
-        return 0;
-    }
-    if a + b <= c {
-        return 0;
-    }
-    if b + c <= a {
-        return 0;
-    }
-    if a + c <= b {
-        return 0;
-    }
-    if a == b {
-        return 1;
-    }
-    if b == c {
-        return 1;
-    }
-    if a == c {
-        return 1;
-    }
-    return 0;
+fn is_equilateral(a: i32, b: i32, c: i32) -> bool {
+    return is_triangle(a, b, c) && a == b && b == c;
 }
 
-fn is_scalene(a: i32, b: i32, c: i32) -> i32 {
-    if a <= 0 {
-        return 0;
-    }
-    if b <= 0 {
-        return 0;
-    }
-    if c <= 0 {
-        return 0;
-    }
-    if a + b <= c {
-        return 0;
-    }
-    if b + c <= a {
-        return 0;
-    }
-    if a + c <= b {
-        return 0;
-    }
-    if a == b {
-        return 0;
-    }
-    if b == c {
-        return 0;
-    }
-    if a == c {
-        return 0;
-    }
-    return 1;
+fn is_isosceles(a: i32, b: i32, c: i32) -> bool {
+    return is_triangle(a, b, c) && (a == b || b == c || a == c);
+}
+
+fn is_scalene(a: i32, b: i32, c: i32) -> bool {
+    return is_triangle(a, b, c) && a != b && b != c && a != c;
 }
 
 let answer = is_equilateral(2, 2, 2);
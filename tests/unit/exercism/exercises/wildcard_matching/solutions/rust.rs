@@ -0,0 +1,31 @@
+fn is_any(c: char) -> bool {
+    return match c {
+        '.' => true,
+        _ => false,
+    };
+}
+
+fn is_star(c: char) -> bool {
+    return match c {
+        '*' => true,
+        _ => false,
+    };
+}
+
+fn is_match(s: &str, p: &str, n: i32, m: i32) -> bool {
+    let mut dp: Vec<Vec<bool>> = Vec::filled(n + 1, Vec::filled(m + 1, false));
+    dp[n][m] = true;
+    for i in (0..=n).rev() {
+        for j in (0..m).rev() {
+            let first_match: bool = i < n && (is_any(p[j]) || p[j] == s[i]);
+            if j + 1 < m && is_star(p[j + 1]) {
+                dp[i][j] = dp[i][j + 2] || (first_match && dp[i + 1][j]);
+            } else {
+                dp[i][j] = first_match && dp[i + 1][j + 1];
+            }
+        }
+    }
+    return dp[0][0];
+}
+
+let answer = is_match("aa", "a*", 2, 2);